@@ -3,7 +3,7 @@
 //!
 //! ```
 //! # use build_array::ArrayBuilder;
-//! let arr: [u8; 3] = ArrayBuilder::new()
+//! let arr: [u8; 3] = ArrayBuilder::<_, 3>::new()
 //!     .push(1)
 //!     .push(2)
 //!     .push(3)
@@ -19,17 +19,50 @@
 //! - [`build_pad`](ArrayBuilder::build_pad).
 //! - [`build_truncate`](ArrayBuilder::build_truncate).
 //! - [`build_pad_truncate`](ArrayBuilder::build_pad_truncate).
+//! - [`build_or_spill`](ArrayBuilder::build_or_spill) (requires the `std` feature).
+//!   With the `std` feature, items pushed past capacity are kept in a
+//!   heap-allocated `Vec` rather than dropped.
+//!
+//! [`ArrayBuilder`] is generic over its backing [`Buffer`], so it isn't
+//! limited to building fixed-size arrays inline. A caller-supplied buffer
+//! lets you build a dynamically-sized, runtime-length collection without
+//! heap allocation, via [`ArrayBuilder::from_buffer`] and
+//! [`build_exact_slice`](ArrayBuilder::build_exact_slice):
+//!
+//! ```
+//! # use build_array::ArrayBuilder;
+//! # use core::mem::MaybeUninit;
+//! let mut storage = [MaybeUninit::uninit(), MaybeUninit::uninit(), MaybeUninit::uninit()];
+//! let mut builder = ArrayBuilder::<_, 0, _>::from_buffer(&mut storage);
+//! builder.push(1).push(2).push(3);
+//! assert_eq!(builder.build_exact_slice().unwrap(), [1, 2, 3]);
+//! ```
+//!
+//! Builders can also be parameterized by an [`ErasePolicy`] to scrub memory
+//! the builder drops - both items rejected by [`push`](ArrayBuilder::push)
+//! and the builder's own slots once a `build_*` call consumes them or the
+//! builder itself is dropped - so secrets don't linger in the backing
+//! buffer. [`Zeroizing`] overwrites the bytes with zero; the
+//! default, [`NoErase`], does nothing. Memory handed back to the caller on a
+//! successful build is never erased.
+//!
+//! [`array_chunks`] turns any iterator into a streaming source of `[T; N]`
+//! arrays, built the same way - the final, possibly-partial group is
+//! available via [`ArrayChunks::into_remainder`].
 //!
 //! # Comparison with other libraries
-//! - [`arrayvec`] requires you to handle over-provision at each call to [`try_push`](arrayvec::ArrayVec::try_push).
+//! - [`arrayvec`](https://docs.rs/arrayvec/latest/arrayvec/) requires you to handle over-provision at each call to `try_push`.
 //! - [`array_builder`](https://docs.rs/array_builder/latest/array_builder/) will
 //!   [`panic!`] on over-provision.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
 
-use arrayvec::ArrayVec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
 
 /// Shorthand for [`ArrayBuilder::new`].
 ///
@@ -40,45 +73,180 @@ use arrayvec::ArrayVec;
 ///     .build_pad_truncate("pad");
 /// ```
 pub const fn new<T, const N: usize>() -> ArrayBuilder<T, N> {
-    ArrayBuilder::new()
+    ArrayBuilder::<T, N>::new()
+}
+
+/// Backing storage for an [`ArrayBuilder`].
+///
+/// Implemented for `[MaybeUninit<T>; N]`, the default inline storage, and for
+/// `&mut [MaybeUninit<T>]`, a caller-supplied, runtime-sized buffer - see
+/// [`ArrayBuilder::from_buffer`].
+///
+/// # Safety
+/// Implementors must return a stable view over the *same* backing memory on
+/// every call: `as_uninit`/`as_uninit_mut` must always point at the same
+/// storage, of the same length, for the lifetime of the `Buffer`, and that
+/// length must equal [`Self::capacity`]. `ArrayBuilder` relies on this to
+/// treat a previously-written prefix of the slots returned here as staying
+/// initialized across calls - a `Buffer` that shrinks, moves, or swaps out
+/// its storage would cause `ArrayBuilder` to read uninitialized memory as a
+/// valid `T`.
+pub unsafe trait Buffer<T> {
+    /// The backing memory, as a slice of possibly-uninitialized slots.
+    fn as_uninit(&self) -> &[MaybeUninit<T>];
+    /// The backing memory, as a mutable slice of possibly-uninitialized slots.
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>];
+    /// The total number of slots in the buffer.
+    fn capacity(&self) -> usize {
+        self.as_uninit().len()
+    }
+}
+
+// SAFETY: an inline array's storage is part of the `Buffer` value itself, so
+// it can't move or change length independently of it.
+unsafe impl<T, const N: usize> Buffer<T> for [MaybeUninit<T>; N] {
+    fn as_uninit(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+
+// SAFETY: a `&mut [MaybeUninit<T>]` always points at the same slice of
+// memory for as long as the reference is alive - reborrowing doesn't change
+// what it points to or its length.
+unsafe impl<T> Buffer<T> for &mut [MaybeUninit<T>] {
+    fn as_uninit(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+
+/// An [`ArrayBuilder`] backed by a caller-supplied, runtime-sized buffer.
+///
+/// `N` is unused for this storage kind - the buffer's length, fixed at
+/// runtime, is what bounds it. See [`ArrayBuilder::from_buffer`].
+pub type SliceBuilder<'b, T, P = NoErase> = ArrayBuilder<T, 0, &'b mut [MaybeUninit<T>], P>;
+
+/// Policy for handling memory an [`ArrayBuilder`] drops: items rejected by
+/// [`push`](ArrayBuilder::push) when full, and the builder's own slots once a
+/// `build_*` call consumes them or the builder itself is dropped. Never
+/// applied to memory handed back to the caller on a successful build.
+pub trait ErasePolicy {
+    /// Called with a pointer to `len` bytes whose value has just been dropped
+    /// or moved out, so the policy can decide whether to scrub them.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads and writes of `len` bytes, properly
+    /// aligned, and not aliased by any other live reference or pointer. The
+    /// value that previously occupied that memory must already have been
+    /// dropped or moved out - implementations may freely overwrite the bytes, but must
+    /// not run a destructor over them.
+    unsafe fn erase(ptr: *mut u8, len: usize);
+}
+
+/// The default [`ErasePolicy`]: leaves dropped memory untouched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoErase;
+
+impl ErasePolicy for NoErase {
+    unsafe fn erase(_ptr: *mut u8, _len: usize) {}
+}
+
+/// An [`ErasePolicy`] that overwrites dropped memory with zero bytes, so
+/// secrets don't linger in the builder's backing storage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Zeroizing;
+
+impl ErasePolicy for Zeroizing {
+    unsafe fn erase(ptr: *mut u8, len: usize) {
+        // Byte-at-a-time `write_volatile` - unlike `write_bytes` plus a
+        // `compiler_fence`, the compiler can't prove a volatile write is
+        // dead and elide it, even once this is inlined under optimization.
+        for i in 0..len {
+            // SAFETY: caller guarantees `ptr` is valid for `len` bytes, so
+            // `ptr.add(i)` for `i < len` is in bounds.
+            unsafe { ptr.add(i).write_volatile(0) };
+        }
+    }
+}
+
+/// Drop `value` in place, then run `P::erase` over its former memory.
+fn erase_and_drop<T, P: ErasePolicy>(mut value: T) {
+    // SAFETY: `value` is a valid, owned `T`. It's `mem::forget`-ten below, so
+    // its destructor never runs a second time.
+    unsafe { core::ptr::drop_in_place(&mut value) };
+    // SAFETY: `&mut value` is valid for `size_of::<T>()` bytes, properly
+    // aligned, and not aliased; its destructor has just been run above.
+    unsafe { P::erase((&mut value as *mut T).cast(), core::mem::size_of::<T>()) };
+    core::mem::forget(value);
+}
+
+/// Run `P::erase` over every slot in `slots`, whose values must already have
+/// been dropped or moved out (or never initialized).
+fn erase_slots<T, P: ErasePolicy>(slots: &mut [MaybeUninit<T>]) {
+    for slot in slots {
+        // SAFETY: `slot.as_mut_ptr()` is valid for `size_of::<T>()` bytes,
+        // properly aligned, and not aliased; the caller guarantees any value
+        // that lived there has already been dropped or moved out.
+        unsafe { P::erase(slot.as_mut_ptr().cast(), core::mem::size_of::<T>()) };
+    }
 }
 
 /// Build an array dynamically without heap allocations.
 ///
 /// See [module documentation](mod@self) for more.
-#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ArrayBuilder<T, const N: usize> {
-    inner: arrayvec::ArrayVec<T, N>,
+pub struct ArrayBuilder<T, const N: usize, B: Buffer<T> = [MaybeUninit<T>; N], P: ErasePolicy = NoErase> {
+    buf: B,
+    len: usize,
     excess: usize,
+    #[cfg(feature = "std")]
+    spill: Vec<T>,
+    marker: PhantomData<(T, P)>,
+}
+
+/// # Safety
+/// Every one of `array`'s `N` elements must be initialized.
+unsafe fn assume_init_array<T, const N: usize>(array: [MaybeUninit<T>; N]) -> [T; N] {
+    // `mem::transmute` can't yet reason about same-sized generic arrays, so
+    // go via a raw pointer cast instead.
+    let ptr = &array as *const [MaybeUninit<T>; N] as *const [T; N];
+    // SAFETY: caller guarantees every element of `array` is initialized.
+    // `array` itself is left behind as `[MaybeUninit<T>; N]`, which runs no
+    // destructors when dropped, so there's no double-drop.
+    unsafe { ptr.read() }
 }
 
-impl<T, const N: usize> ArrayBuilder<T, N> {
+impl<T, const N: usize, P: ErasePolicy> ArrayBuilder<T, N, [MaybeUninit<T>; N], P> {
     /// Create a new, empty builder.
     pub const fn new() -> Self {
         Self {
-            inner: ArrayVec::new_const(),
+            // SAFETY: an array of `MaybeUninit<T>` doesn't itself require
+            // initialization - each element is independently allowed to be
+            // uninitialized.
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
             excess: 0,
+            #[cfg(feature = "std")]
+            spill: Vec::new(),
+            marker: PhantomData,
         }
     }
-    /// Insert an item into the builder.
+    /// Take ownership of the filled buffer, resetting `len` to zero.
     ///
-    /// If the builder is already full, the item is immediately dropped.
-    pub fn push(&mut self, item: T) -> &mut Self {
-        if self.inner.try_push(item).is_err() {
-            self.excess += 1
-        };
-        self
-    }
-    fn pad_with(&mut self, mut f: impl FnMut() -> T) {
-        for _ in 0..self.inner.remaining_capacity() {
-            self.inner.push(f())
-        }
-    }
-    fn error(&self) -> Error {
-        Error {
-            expected: N,
-            actual: self.inner.len() + self.excess,
-        }
+    /// The caller must have already checked `self.len == N`.
+    fn take_array(&mut self) -> [T; N] {
+        debug_assert_eq!(self.len, N);
+        // SAFETY: see `Self::new` - an array of `MaybeUninit<T>` doesn't
+        // itself require initialization.
+        let empty = unsafe { MaybeUninit::uninit().assume_init() };
+        let full = core::mem::replace(&mut self.buf, empty);
+        self.len = 0;
+        // SAFETY: `self.len` was `N`, so every slot in `full` was initialized.
+        unsafe { assume_init_array(full) }
     }
     /// Pad out the array, returning an [`Err`] if there were too many calls to [`Self::push`].
     /// The builder remains unchanged in the [`Err`] case.
@@ -97,11 +265,10 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
         if self.excess > 0 {
             return Err(self.error());
         }
-        self.pad_with(|| item.clone());
-        match self.inner.take().into_inner() {
-            Ok(it) => Ok(it),
-            Err(_) => unreachable!("we've just padded"),
+        while self.len < N {
+            self.push(item.clone());
         }
+        Ok(self.take_array())
     }
     /// Pad out the array, ignoring if there were too many calls to [`Self::push`].
     /// The builder is restored to an empty state.
@@ -119,12 +286,15 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     where
         T: Clone,
     {
-        self.pad_with(|| item.clone());
+        while self.len < N {
+            self.push(item.clone());
+        }
         self.excess = 0;
-        match self.inner.take().into_inner() {
-            Ok(it) => it,
-            Err(_) => unreachable!("we've just padded"),
+        #[cfg(feature = "std")]
+        for item in self.spill.drain(..) {
+            erase_and_drop::<T, P>(item);
         }
+        self.take_array()
     }
     /// Build the array, ignoring if there were too many calls to [`Self::push`].
     /// The builder is restored to an empty state, and remains unchanged in the
@@ -138,15 +308,17 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     /// ArrayBuilder::<&str, 1>::new().build_truncate().unwrap_err();
     /// ```
     pub fn build_truncate(&mut self) -> Result<[T; N], Error> {
-        match self.inner.remaining_capacity() == 0 {
-            true => match self.inner.take().into_inner() {
-                Ok(it) => Ok(it),
-                Err(_) => unreachable!("we've just checked the capacity"),
-            },
-            false => Err(self.error()),
+        if self.len == N {
+            #[cfg(feature = "std")]
+            for item in self.spill.drain(..) {
+                erase_and_drop::<T, P>(item);
+            }
+            self.excess = 0;
+            Ok(self.take_array())
+        } else {
+            Err(self.error())
         }
     }
-
     /// Require exactly `N` calls to [`Self::push`].
     /// The builder remains unchanged in the [`Err`] case.
     /// ```
@@ -157,11 +329,142 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     /// ArrayBuilder::<_, 2>::new().push("just").push("right").build_exact().unwrap();
     /// ```
     pub fn build_exact(&mut self) -> Result<[T; N], Error> {
-        if self.inner.remaining_capacity() == 0 && self.excess == 0 {
-            match self.inner.take().into_inner() {
-                Ok(it) => Ok(it),
-                Err(_) => unreachable!("remaining capacity is zero"),
+        if self.len == N && self.excess == 0 {
+            Ok(self.take_array())
+        } else {
+            Err(self.error())
+        }
+    }
+    /// Build the array, or recover every pushed item if there were too many
+    /// or too few calls to [`Self::push`].
+    ///
+    /// Unlike [`Self::build_truncate`], no items are discarded: on failure,
+    /// every item ever pushed - in the order they were pushed - is returned
+    /// in the [`Vec`]. The builder is restored to an empty state.
+    ///
+    /// ```
+    /// # use build_array::ArrayBuilder;
+    /// let arr = ArrayBuilder::<_, 2>::new().push("just").push("right").build_or_spill().unwrap();
+    /// assert_eq!(arr, ["just", "right"]);
+    ///
+    /// let spilled = ArrayBuilder::<_, 1>::new().push("first").push("second").build_or_spill().unwrap_err();
+    /// assert_eq!(spilled, ["first", "second"]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn build_or_spill(&mut self) -> Result<[T; N], Vec<T>> {
+        if self.len == N && self.excess == 0 {
+            Ok(self.take_array())
+        } else {
+            let mut spilled = self.drain_to_vec();
+            spilled.append(&mut self.spill);
+            self.excess = 0;
+            Err(spilled)
+        }
+    }
+}
+
+impl<'b, T, P: ErasePolicy> ArrayBuilder<T, 0, &'b mut [MaybeUninit<T>], P> {
+    /// Create a builder backed by a caller-supplied buffer, for
+    /// dynamically-sized array building without heap allocation.
+    ///
+    /// Finish with [`Self::build_exact_slice`] rather than the fixed-size
+    /// `build_*` methods, since the builder doesn't own the buffer and so
+    /// can't move items out of it into a `[T; N]`.
+    pub fn from_buffer(buf: &'b mut [MaybeUninit<T>]) -> Self {
+        Self {
+            buf,
+            len: 0,
+            excess: 0,
+            #[cfg(feature = "std")]
+            spill: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize, P: ErasePolicy> Default for ArrayBuilder<T, N, [MaybeUninit<T>; N], P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, B: Buffer<T>, P: ErasePolicy> ArrayBuilder<T, N, B, P> {
+    fn try_insert(&mut self, item: T) -> Result<(), T> {
+        let buf = self.buf.as_uninit_mut();
+        if self.len < buf.len() {
+            buf[self.len].write(item);
+            self.len += 1;
+            Ok(())
+        } else {
+            Err(item)
+        }
+    }
+    fn error(&self) -> Error {
+        Error {
+            expected: self.buf.capacity(),
+            actual: self.len + self.excess,
+        }
+    }
+    /// Insert an item into the builder.
+    ///
+    /// If the builder is already full, the item is immediately dropped -
+    /// unless the `std` feature is enabled, in which case it is kept so that
+    /// [`Self::build_or_spill`] can still return it.
+    pub fn push(&mut self, item: T) -> &mut Self {
+        if let Err(item) = self.try_push(item) {
+            #[cfg(feature = "std")]
+            self.spill.push(item);
+            #[cfg(not(feature = "std"))]
+            erase_and_drop::<T, P>(item);
+        }
+        self
+    }
+    /// Insert an item into the builder, handing it back if the builder is
+    /// already full.
+    ///
+    /// Unlike [`Self::push`], the item is not dropped on overflow - it is
+    /// returned to the caller so it isn't lost. `excess` is still
+    /// incremented, so later `build_*` calls continue to report the correct
+    /// count.
+    ///
+    /// ```
+    /// # use build_array::ArrayBuilder;
+    /// let mut builder = ArrayBuilder::<_, 1>::new();
+    /// assert!(builder.try_push("first").is_ok());
+    /// assert_eq!(builder.try_push("second"), Err("second"));
+    /// ```
+    pub fn try_push(&mut self, item: T) -> Result<&mut Self, T> {
+        match self.try_insert(item) {
+            Ok(()) => Ok(self),
+            Err(item) => {
+                self.excess += 1;
+                Err(item)
             }
+        }
+    }
+    /// Require exactly [`Buffer::capacity`] calls to [`Self::push`], returning
+    /// a view of the filled buffer.
+    ///
+    /// Unlike [`Self::build_exact`], the builder is not reset and the items
+    /// are not moved out - since a caller-supplied buffer isn't owned by the
+    /// builder, this is the only way to finish building into one.
+    /// The builder remains unchanged in the [`Err`] case.
+    ///
+    /// ```
+    /// # use build_array::ArrayBuilder;
+    /// # use core::mem::MaybeUninit;
+    /// let mut storage = [MaybeUninit::uninit(), MaybeUninit::uninit()];
+    /// let mut builder = ArrayBuilder::<_, 0, _>::from_buffer(&mut storage);
+    /// builder.push("just").push("right");
+    /// assert_eq!(builder.build_exact_slice().unwrap(), ["just", "right"]);
+    /// ```
+    pub fn build_exact_slice(&mut self) -> Result<&mut [T], Error> {
+        if self.len == self.buf.capacity() && self.excess == 0 {
+            let len = self.len;
+            let slots = &mut self.buf.as_uninit_mut()[..len];
+            // SAFETY: the first `len` slots have been initialized by
+            // `push`/`try_push`.
+            Ok(unsafe { &mut *(slots as *mut [MaybeUninit<T>] as *mut [T]) })
         } else {
             Err(self.error())
         }
@@ -170,24 +473,138 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     ///
     /// Does not include excess items.
     pub fn as_slice(&self) -> &[T] {
-        self.inner.as_slice()
+        let slots = &self.buf.as_uninit()[..self.len];
+        // SAFETY: the first `len` slots have been initialized by
+        // `push`/`try_push`.
+        unsafe { &*(slots as *const [MaybeUninit<T>] as *const [T]) }
     }
     /// Return the current collection of items in the array.
     ///
     /// Does not include excess items.
     pub fn as_mut_slice(&mut self) -> &mut [T] {
-        self.inner.as_mut_slice()
+        let len = self.len;
+        let slots = &mut self.buf.as_uninit_mut()[..len];
+        // SAFETY: the first `len` slots have been initialized by
+        // `push`/`try_push`.
+        unsafe { &mut *(slots as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+    /// Move every initialized item out into a freshly-allocated [`Vec`],
+    /// resetting `len` to zero.
+    #[cfg(feature = "std")]
+    fn drain_to_vec(&mut self) -> Vec<T> {
+        let len = self.len;
+        self.len = 0;
+        let slots = &mut self.buf.as_uninit_mut()[..len];
+        let mut drained = Vec::with_capacity(len);
+        for slot in slots.iter_mut() {
+            // SAFETY: the first `len` slots have been initialized by
+            // `push`/`try_push`, and each is moved out exactly once.
+            drained.push(unsafe { slot.assume_init_read() });
+        }
+        // `assume_init_read` above is a bitwise copy, not a move that empties
+        // the slot - the bytes are still sitting in `self.buf` even though
+        // `drained` now owns the logical value, so the policy needs to run
+        // here too, not just in `Drop`.
+        erase_slots::<T, P>(slots);
+        drained
+    }
+}
+
+impl<T, const N: usize, B: Buffer<T>, P: ErasePolicy> Drop for ArrayBuilder<T, N, B, P> {
+    fn drop(&mut self) {
+        let len = self.len;
+        let slots = &mut self.buf.as_uninit_mut()[..len];
+        // SAFETY: the first `len` slots have been initialized by
+        // `push`/`try_push`, and the builder is being dropped, so nothing
+        // else can observe them again afterwards.
+        unsafe { core::ptr::drop_in_place(slots as *mut [MaybeUninit<T>] as *mut [T]) };
+        erase_slots::<T, P>(slots);
+        #[cfg(feature = "std")]
+        for item in self.spill.drain(..) {
+            erase_and_drop::<T, P>(item);
+        }
+    }
+}
+
+impl<T: Clone, const N: usize, P: ErasePolicy> Clone for ArrayBuilder<T, N, [MaybeUninit<T>; N], P> {
+    fn clone(&self) -> Self {
+        let mut new = Self::new();
+        for item in self.as_slice() {
+            new.push(item.clone());
+        }
+        new.excess = self.excess;
+        #[cfg(feature = "std")]
+        new.spill.clone_from(&self.spill);
+        new
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, B: Buffer<T>, P: ErasePolicy> fmt::Debug for ArrayBuilder<T, N, B, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayBuilder")
+            .field("items", &self.as_slice())
+            .field("excess", &self.excess)
+            .finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize, B: Buffer<T>, P: ErasePolicy> PartialEq for ArrayBuilder<T, N, B, P> {
+    fn eq(&self, other: &Self) -> bool {
+        let equal = self.as_slice() == other.as_slice() && self.excess == other.excess;
+        // Two builders with identical `as_slice()`/`excess` can still be
+        // headed for different `Vec`s on `build_or_spill` if their spilled
+        // items differ, so that has to factor into equality too.
+        #[cfg(feature = "std")]
+        let equal = equal && self.spill == other.spill;
+        equal
+    }
+}
+impl<T: Eq, const N: usize, B: Buffer<T>, P: ErasePolicy> Eq for ArrayBuilder<T, N, B, P> {}
+
+impl<T: PartialOrd, const N: usize, B: Buffer<T>, P: ErasePolicy> PartialOrd for ArrayBuilder<T, N, B, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.as_slice().partial_cmp(other.as_slice()) {
+            Some(core::cmp::Ordering::Equal) => match self.excess.partial_cmp(&other.excess) {
+                #[cfg(feature = "std")]
+                Some(core::cmp::Ordering::Equal) => self.spill.partial_cmp(&other.spill),
+                other => other,
+            },
+            other => other,
+        }
+    }
+}
+impl<T: Ord, const N: usize, B: Buffer<T>, P: ErasePolicy> Ord for ArrayBuilder<T, N, B, P> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match self
+            .as_slice()
+            .cmp(other.as_slice())
+            .then(self.excess.cmp(&other.excess))
+        {
+            #[cfg(feature = "std")]
+            core::cmp::Ordering::Equal => self.spill.cmp(&other.spill),
+            other => other,
+        }
+    }
+}
+impl<T: core::hash::Hash, const N: usize, B: Buffer<T>, P: ErasePolicy> core::hash::Hash
+    for ArrayBuilder<T, N, B, P>
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+        self.excess.hash(state);
+        #[cfg(feature = "std")]
+        self.spill.hash(state);
     }
 }
 
-impl<T, const N: usize> Extend<T> for ArrayBuilder<T, N> {
+impl<T, const N: usize, B: Buffer<T>, P: ErasePolicy> Extend<T> for ArrayBuilder<T, N, B, P> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for it in iter {
             self.push(it);
         }
     }
 }
-impl<T, const N: usize> FromIterator<T> for ArrayBuilder<T, N> {
+impl<T, const N: usize, P: ErasePolicy> FromIterator<T> for ArrayBuilder<T, N, [MaybeUninit<T>; N], P> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut this = Self::new();
         this.extend(iter);
@@ -218,3 +635,157 @@ impl fmt::Display for Error {
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
+
+/// Group `iter`'s items into non-overlapping `[T; N]` arrays, using an
+/// [`ArrayBuilder`] internally rather than allocating.
+///
+/// The final, possibly-partial group - fewer than `N` items - is available
+/// from [`ArrayChunks::into_remainder`] once iteration has stopped.
+///
+/// ```
+/// # use build_array::array_chunks;
+/// let mut chunks = array_chunks::<_, _, 2>(1..=5);
+/// assert_eq!(chunks.next(), Some([1, 2]));
+/// assert_eq!(chunks.next(), Some([3, 4]));
+/// assert_eq!(chunks.next(), None);
+/// assert_eq!(chunks.into_remainder().collect::<std::vec::Vec<_>>(), [5]);
+/// ```
+pub fn array_chunks<I, T, const N: usize>(iter: I) -> ArrayChunks<I::IntoIter, T, N>
+where
+    I: IntoIterator<Item = T>,
+{
+    assert!(N != 0, "chunk size must be non-zero");
+    ArrayChunks {
+        iter: iter.into_iter(),
+        builder: ArrayBuilder::new(),
+        exhausted: false,
+    }
+}
+
+/// Iterator adaptor, returned by [`array_chunks`], that groups its source
+/// iterator's items into `[T; N]` arrays.
+///
+/// See [module documentation](mod@self) for more.
+pub struct ArrayChunks<I, T, const N: usize> {
+    iter: I,
+    builder: ArrayBuilder<T, N>,
+    exhausted: bool,
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, I::Item, N> {
+    type Item = [I::Item; N];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        while self.builder.len < N {
+            match self.iter.next() {
+                Some(item) => {
+                    self.builder.push(item);
+                }
+                None => {
+                    self.exhausted = true;
+                    return None;
+                }
+            }
+        }
+        self.builder.build_exact().ok()
+    }
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, I::Item, N> {
+    /// Iterate over the final, partial group - fewer than `N` items - left
+    /// buffered once the underlying iterator is exhausted.
+    ///
+    /// Empty if the source iterator's length was a multiple of `N`, or if
+    /// iteration hasn't reached the end yet.
+    pub fn into_remainder(self) -> Remainder<I::Item, N> {
+        let ArrayChunks { builder, .. } = self;
+        Remainder { builder, index: 0 }
+    }
+}
+
+/// The partial final group left over from an [`ArrayChunks`], returned by
+/// [`ArrayChunks::into_remainder`].
+pub struct Remainder<T, const N: usize> {
+    builder: ArrayBuilder<T, N>,
+    index: usize,
+}
+
+impl<T, const N: usize> Iterator for Remainder<T, N> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.index >= self.builder.len {
+            return None;
+        }
+        // SAFETY: slots `[0, len)` were initialized by `ArrayBuilder::push`,
+        // and `index` only increases, so each slot is read out exactly once.
+        let item = unsafe { self.builder.buf[self.index].assume_init_read() };
+        self.index += 1;
+        Some(item)
+    }
+}
+
+impl<T, const N: usize> Drop for Remainder<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: slots `[index, len)` haven't been yielded by `next`, so
+        // they're still initialized; slots before `index` were already moved
+        // out.
+        let remaining = &mut self.builder.buf[self.index..self.builder.len];
+        unsafe { core::ptr::drop_in_place(remaining as *mut [MaybeUninit<T>] as *mut [T]) };
+        // Prevent `self.builder`'s own `Drop` - which runs right after this
+        // one - from dropping the same slots again.
+        self.builder.len = 0;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    /// Read `value`'s backing bytes without going through any of its own
+    /// methods, so a `Zeroizing` erasure shows up even if `T` has no way to
+    /// observe its own representation (e.g. a bare integer).
+    fn bytes_of<T>(value: &T) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(value as *const T as *const u8, core::mem::size_of::<T>()) }
+    }
+
+    #[test]
+    fn build_or_spill_erases_the_backing_buffer_on_the_spill_path() {
+        let mut builder = ArrayBuilder::<u64, 2, _, Zeroizing>::new();
+        builder.push(0xDEADBEEFCAFEBABE);
+        // Grab a pointer at the slot before it's moved out, so we can check
+        // the bytes left behind once `build_or_spill` is done with it.
+        let slot_ptr = builder.buf[0].as_ptr();
+        let spilled = builder.build_or_spill().unwrap_err();
+        assert_eq!(spilled, [0xDEADBEEFCAFEBABE]);
+        // SAFETY: the slot is still part of `builder.buf`'s storage, which is
+        // still alive; it no longer holds a live `u64`, so reading it as
+        // bytes (rather than as a `u64`) doesn't run or duplicate a
+        // destructor.
+        let leftover = unsafe { core::slice::from_raw_parts(slot_ptr as *const u8, core::mem::size_of::<u64>()) };
+        assert_eq!(leftover, [0u8; 8], "secret bytes were left behind after build_or_spill");
+    }
+
+    #[test]
+    fn no_erase_leaves_dropped_memory_untouched() {
+        let mut builder = core::mem::ManuallyDrop::new(ArrayBuilder::<u64, 1, _, NoErase>::new());
+        builder.push(0x1122334455667788);
+        let slot_ptr = builder.buf[0].as_ptr();
+        // Drop in place, so `slot_ptr` still points at the memory `Drop` ran
+        // over (a by-value `drop` would move the builder first).
+        unsafe { core::mem::ManuallyDrop::drop(&mut builder) };
+        assert_eq!(bytes_of(unsafe { &*slot_ptr }), 0x1122334455667788u64.to_ne_bytes());
+    }
+
+    #[test]
+    fn equality_distinguishes_differently_spilled_content() {
+        let mut a = ArrayBuilder::<_, 2>::new();
+        a.push(1).push(2).push(3);
+        let mut b = ArrayBuilder::<_, 2>::new();
+        b.push(1).push(2).push(99);
+
+        assert_ne!(a, b);
+        assert_ne!(a.build_or_spill().unwrap_err(), b.build_or_spill().unwrap_err());
+    }
+}